@@ -1,4 +1,4 @@
-use std::{env, path::{Path, PathBuf}};
+use std::{env, path::{Component, Path, PathBuf}};
 
 use dirs;
 
@@ -7,6 +7,76 @@ pub struct RPath{
     path:PathBuf,
 }
 
+///
+/// A single, typed segment of an `RPath`, as yielded by `RPath::components()`.
+///
+/// Mirrors `std::path::Component`, but folds `Prefix` down to a unit variant since `RPath`
+/// does not expose the platform-specific prefix payload.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RComponent<'a> {
+    Prefix,
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a str),
+}
+
+///
+/// The error type returned by the `try_`-prefixed fallible counterparts of `RPath`'s methods.
+///
+/// The panicking methods (`basename`, `dirname`, `extension`, `pwd`, `gethomedir`, ...) are thin
+/// wrappers around their `try_` counterparts that print the `Display` form of this error and
+/// call `std::process::exit(1)` (except that `extension` returns `""` for `NoExtension` rather
+/// than exiting), so library consumers that cannot tolerate an aborting host process should
+/// prefer the `try_` methods directly.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RPathError {
+    InvalidUtf8(String),
+    NoBasename,
+    NoDirname,
+    NoExtension,
+    CurrentDirUnavailable(String),
+    HomeDirUnavailable,
+}
+
+impl std::fmt::Display for RPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RPathError::InvalidUtf8(what) => write!(f, "Failed to convert {} from OsStr to str.", what),
+            RPathError::NoBasename => write!(f, "Failed to get basename."),
+            RPathError::NoDirname => write!(f, "Failed to get dirname."),
+            RPathError::NoExtension => write!(f, "Filename extension not found."),
+            RPathError::CurrentDirUnavailable(reason) => write!(f, "Failed to get current dir: {}", reason),
+            RPathError::HomeDirUnavailable => write!(f, "Failed to get homedir."),
+        }
+    }
+}
+
+impl std::error::Error for RPathError {}
+
+///
+/// Splits a basename into `(stem, extension)` following `std::path`'s rules: a leading dot is
+/// part of the stem, and the extension is the substring after the final interior dot.
+///
+#[cfg(feature = "Management")]
+fn split_stem_and_extension(basename: &str) -> (&str, Option<&str>) {
+    if basename == "." || basename == ".." {
+        return (basename, None);
+    }
+
+    let search_start = if basename.starts_with('.') { 1 } else { 0 };
+
+    match basename[search_start..].rfind('.') {
+        Some(offset) => {
+            let split_at = search_start + offset;
+            (&basename[..split_at], Some(&basename[split_at + 1..]))
+        },
+        None => (basename, None),
+    }
+}
+
 impl RPath{
     ///
     ///  Allocates an empty `RPath`
@@ -110,21 +180,28 @@ impl RPath{
     /// ```
     #[cfg(feature = "Management")]
     pub fn basename(&self) -> &str {
-        let basename = match self.path.file_name() {
-            Some(filename) => match filename.to_str() {
-                Some(a) => a,
-                None => {
-                    eprintln!("Failed to convert basename from OsStr to str.");
-                    std::process::exit(1);
-                },
-            },
-            None => {
-                eprintln!("Failed to get basename.");
-                std::process::exit(1);
-            },
-        };
+        self.try_basename().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    }
 
-        basename
+    ///
+    /// Fallible counterpart of `basename()` — returns `Err(RPathError)` instead of aborting the
+    /// process.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp/abc.txt");
+    /// assert_eq!(rpath.try_basename(), Ok("abc.txt"));
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn try_basename(&self) -> Result<&str, RPathError> {
+        let filename = self.path.file_name().ok_or(RPathError::NoBasename)?;
+        filename.to_str().ok_or_else(|| RPathError::InvalidUtf8("basename".to_string()))
     }
 
     /// 
@@ -161,17 +238,29 @@ impl RPath{
     /// ```
     #[cfg(feature = "Management")]
     pub fn dirname(&self) -> RPath {
-        let dirpath = match self.path.parent() {
-            Some(a) => a.to_path_buf(),
-            None => {
-                eprintln!("Failed to get dirname.");
-                std::process::exit(1);
-            },
-        };
+        self.try_dirname().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    }
 
-        Self {
-            path: dirpath,
-        }
+    ///
+    /// Fallible counterpart of `dirname()` — returns `Err(RPathError)` instead of aborting the
+    /// process.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp/abc.txt");
+    /// assert_eq!(rpath.try_dirname(), Ok(RPath::from("/temp")));
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn try_dirname(&self) -> Result<RPath, RPathError> {
+        self.path.parent()
+            .map(|p| RPath { path: p.to_path_buf() })
+            .ok_or(RPathError::NoDirname)
     }
 
     /// 
@@ -191,31 +280,150 @@ impl RPath{
         RPath::from(dirname).join(self.basename())
     }
 
-    /// 
-    /// Returns the `extension` of the basename if any.. else returns the basename
+    ///
+    /// Returns the extension of the basename, following the same rules as
+    /// `std::path::Path::extension`: a leading dot is part of the stem (so `.bashrc` has no
+    /// extension), and the extension is the substring after the final *interior* dot (so
+    /// `archive.tar.gz` has extension `gz`, not `tar.gz`).
+    ///
+    /// Returns `""` for a basename with no extension (e.g. `Makefile`, `README`, `.bashrc`) —
+    /// this is an ordinary, valid input, not a failure. Aborts the process (like the rest of
+    /// this crate's non-`try_`-prefixed methods) only if the basename itself cannot be
+    /// determined at all, e.g. `RPath::from(".")`. Use `try_extension` to distinguish "no
+    /// extension" from that abort case without aborting.
     /// ### Usage
     /// ```
     /// use rustypath::RPath;
-    /// 
+    ///
     /// let rpath = RPath::from("/temp").join("abc.txt");
-    /// 
     /// assert_eq!(rpath.extension(), "txt");
-    /// 
+    ///
+    /// let makefile = RPath::from("/temp").join("Makefile");
+    /// assert_eq!(makefile.extension(), "");
     /// ```
     #[cfg(feature = "Management")]
     pub fn extension(&self) -> &str {
-        let basename = self.basename();
-        let parts: Vec<&str> = basename.split(".").collect();
-        if parts.len() >= 2 {
-            parts.last().unwrap()
-        } else if parts.len() == 1 {
-            self.basename()
-        } else {
-            eprintln!("Filename extension not found.");
-            std::process::exit(1);
+        match self.try_extension() {
+            Ok(ext) => ext,
+            Err(RPathError::NoExtension) => "",
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            },
         }
     }
 
+    ///
+    /// Fallible counterpart of `extension()` — returns `Err(RPathError::NoExtension)` when the
+    /// basename has no extension, instead of aborting the process.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp").join("abc.txt");
+    /// assert_eq!(rpath.try_extension(), Ok("txt"));
+    ///
+    /// let dotfile = RPath::from("/home").join(".bashrc");
+    /// assert!(dotfile.try_extension().is_err());
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn try_extension(&self) -> Result<&str, RPathError> {
+        let basename = self.try_basename()?;
+        split_stem_and_extension(basename).1.ok_or(RPathError::NoExtension)
+    }
+
+    ///
+    /// Returns the basename without its extension, following the same rules as
+    /// `std::path::Path::file_stem`: a leading dot is part of the stem, so `.bashrc`'s stem is
+    /// `.bashrc` itself.
+    /// ### Usage
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp").join("archive.tar.gz");
+    /// assert_eq!(rpath.stem(), "archive.tar");
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn stem(&self) -> &str {
+        self.try_stem().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    }
+
+    ///
+    /// Fallible counterpart of `stem()` — returns `Err(RPathError)` instead of aborting the
+    /// process.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp").join("archive.tar.gz");
+    /// assert_eq!(rpath.try_stem(), Ok("archive.tar"));
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn try_stem(&self) -> Result<&str, RPathError> {
+        let basename = self.try_basename()?;
+        Ok(split_stem_and_extension(basename).0)
+    }
+
+    ///
+    /// Creates a new `RPath` with the extension swapped for `ext` (or appended, if the basename
+    /// had none), preserving the directory and stem.
+    ///
+    /// Aborts the process (like the rest of this crate's non-`try_`-prefixed methods) if the
+    /// path has no basename to rewrite, e.g. `RPath::from(".")`. Prefer `try_with_extension` to
+    /// handle that case instead of aborting.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp").join("abc.txt");
+    /// assert_eq!(rpath.with_extension("md"), RPath::from("/temp/abc.md"));
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn with_extension<S: AsRef<str>>(&self, ext: S) -> RPath {
+        self.try_with_extension(ext).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    }
+
+    ///
+    /// Fallible counterpart of `with_extension()` — returns `Err(RPathError)` instead of
+    /// aborting the process when the path has no basename to rewrite.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp").join("abc.txt");
+    /// assert_eq!(rpath.try_with_extension("md"), Ok(RPath::from("/temp/abc.md")));
+    ///
+    /// assert!(RPath::from(".").try_with_extension("txt").is_err());
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn try_with_extension<S: AsRef<str>>(&self, ext: S) -> Result<RPath, RPathError> {
+        let stem = self.try_stem()?;
+        let ext = ext.as_ref();
+
+        let new_basename = if ext.is_empty() {
+            stem.to_string()
+        } else {
+            format!("{}.{}", stem, ext)
+        };
+
+        let dirname = self.try_dirname()?;
+        Ok(dirname.join(new_basename))
+    }
+
     ///
     /// Returns an iterator over the entries within a directory.
     /// 
@@ -263,17 +471,29 @@ impl RPath{
     /// ```
     #[cfg(feature = "Management")]
     pub fn pwd() -> RPath {
-        let pwd: PathBuf = match env::current_dir() {
-            Ok(value) => value,
-            Err(_err) => {
-                eprintln!("Failed to get current dir.");
-                std::process::exit(1);
-            },
-        };
+        Self::try_pwd().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    }
 
-        RPath {
-            path: pwd,
-        }
+    ///
+    /// Fallible counterpart of `pwd()` — returns `Err(RPathError)` instead of aborting the
+    /// process.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let current_directory = RPath::try_pwd();
+    /// assert!(current_directory.is_ok());
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn try_pwd() -> Result<RPath, RPathError> {
+        env::current_dir()
+            .map(|path| RPath { path })
+            .map_err(|err| RPathError::CurrentDirUnavailable(err.to_string()))
     }
 
     ///
@@ -288,14 +508,27 @@ impl RPath{
     /// ```
     #[cfg(feature = "Management")]
     pub fn gethomedir() -> RPath {
-        let home = match dirs::home_dir() {
-            Some(a) => a,
-            None => {
-                eprintln!("Failed to get homedir.");
-                std::process::exit(1); },
-        };
-        
-        RPath::from(&home)
+        Self::try_gethomedir().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        })
+    }
+
+    ///
+    /// Fallible counterpart of `gethomedir()` — returns `Err(RPathError)` instead of aborting
+    /// the process.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let home = RPath::try_gethomedir();
+    /// println!("{:?}", home);
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn try_gethomedir() -> Result<RPath, RPathError> {
+        dirs::home_dir().map(|home| RPath::from(&home)).ok_or(RPathError::HomeDirUnavailable)
     }
 
     ///
@@ -321,7 +554,194 @@ impl RPath{
         RPath{path}
     }
 
-    /// 
+    ///
+    /// Resolves `.` and `..` components of the path lexically, without touching the filesystem.
+    ///
+    /// Unlike `expand()`, this never looks at the filesystem and never fails: `.` components are
+    /// dropped, and each `..` pops the previous normal component off. A `..` directly under a
+    /// root or prefix is dropped (there is nothing above the root to go to), while a `..` with
+    /// only other `..` components (or nothing) before it in a relative path is kept.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("foo/bar/../baz").normalize();
+    /// assert_eq!(rpath, RPath::from("foo/baz"));
+    ///
+    /// let rpath = RPath::from("../foo").normalize();
+    /// assert_eq!(rpath, RPath::from("../foo"));
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn normalize(&self) -> RPath {
+        let mut stack: Vec<Component> = Vec::new();
+
+        for component in self.path.components() {
+            match component {
+                Component::CurDir => {},
+                Component::ParentDir => match stack.last() {
+                    Some(Component::Normal(_)) => { stack.pop(); },
+                    Some(Component::RootDir) | Some(Component::Prefix(_)) => {},
+                    Some(Component::ParentDir) | None => stack.push(component),
+                    _ => stack.push(component),
+                },
+                other => stack.push(other),
+            }
+        }
+
+        let mut normalized = PathBuf::new();
+        for component in stack {
+            normalized.push(component.as_os_str());
+        }
+
+        RPath { path: normalized }
+    }
+
+    ///
+    /// Returns an iterator over the typed segments of the path, mirroring
+    /// `std::path::Path::components`.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::{RPath, RComponent};
+    ///
+    /// let rpath = RPath::from("/temp/abc.txt");
+    /// let segments: Vec<RComponent> = rpath.components().collect();
+    ///
+    /// assert_eq!(segments, vec![RComponent::RootDir, RComponent::Normal("temp"), RComponent::Normal("abc.txt")]);
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn components(&self) -> impl Iterator<Item = RComponent<'_>> {
+        self.try_components().map(|component| component.unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }))
+    }
+
+    ///
+    /// Fallible counterpart of `components()` — yields `Err(RPathError)` for any segment that is
+    /// not valid UTF-8, instead of aborting the process.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::{RPath, RComponent};
+    ///
+    /// let rpath = RPath::from("/temp/abc.txt");
+    /// let segments: Vec<RComponent> = rpath.try_components().collect::<Result<_, _>>().unwrap();
+    ///
+    /// assert_eq!(segments, vec![RComponent::RootDir, RComponent::Normal("temp"), RComponent::Normal("abc.txt")]);
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn try_components(&self) -> impl Iterator<Item = Result<RComponent<'_>, RPathError>> {
+        self.path.components().map(|component| match component {
+            Component::Prefix(_) => Ok(RComponent::Prefix),
+            Component::RootDir => Ok(RComponent::RootDir),
+            Component::CurDir => Ok(RComponent::CurDir),
+            Component::ParentDir => Ok(RComponent::ParentDir),
+            Component::Normal(segment) => match segment.to_str() {
+                Some(a) => Ok(RComponent::Normal(a)),
+                None => Err(RPathError::InvalidUtf8("path component".to_string())),
+            },
+        })
+    }
+
+    ///
+    /// Returns the remainder of the path after stripping `base`, or `None` if the path does not
+    /// start with `base`.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp/abc.txt");
+    /// assert_eq!(rpath.strip_prefix("/temp"), Some(RPath::from("abc.txt")));
+    /// assert_eq!(rpath.strip_prefix("/other"), None);
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn strip_prefix<T: AsRef<Path>>(&self, base: T) -> Option<RPath> {
+        self.path.strip_prefix(base).ok().map(|p| RPath { path: p.to_path_buf() })
+    }
+
+    ///
+    /// Computes the shortest relative path that, when joined to `base`, yields `self`.
+    ///
+    /// `self` and `base` are first resolved lexically via `normalize()` (so any `.`/`..` they
+    /// already contain is collapsed), then the shared leading components of the two normalized
+    /// paths are walked, emitting one `..` per remaining `base` component followed by the
+    /// remaining `self` components. Returns `None` when the two paths have incompatible
+    /// roots/prefixes (e.g. one is absolute and the other relative, or they sit under different
+    /// drive letters), since there is then no relative path connecting them. Also returns `None`
+    /// when the normalized `base` still has `..` components past the shared part, since the
+    /// directories those `..` climb into are not known lexically.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/a/b/c");
+    /// let base = RPath::from("/a/x/y");
+    ///
+    /// assert_eq!(rpath.relative_to(&base), Some(RPath::from("../../b/c")));
+    ///
+    /// // inputs need not already be lexically clean; they are normalized first.
+    /// let rpath = RPath::from("a/b/../../c");
+    /// let base = RPath::from("a");
+    ///
+    /// assert_eq!(rpath.relative_to(&base), Some(RPath::from("../c")));
+    /// ```
+    #[cfg(feature = "Management")]
+    pub fn relative_to<T: AsRef<Path>>(&self, base: T) -> Option<RPath> {
+        let this = self.normalize();
+        let base = RPath::from(base.as_ref()).normalize();
+
+        if this.path.is_absolute() != base.path.is_absolute() {
+            return None;
+        }
+
+        let self_comps: Vec<Component> = this.path.components().collect();
+        let base_comps: Vec<Component> = base.path.components().collect();
+
+        let mut shared = 0;
+        while shared < self_comps.len()
+            && shared < base_comps.len()
+            && self_comps[shared] == base_comps[shared]
+        {
+            shared += 1;
+        }
+
+        let leftover_root = |comps: &[Component]| {
+            comps.iter().any(|c| matches!(c, Component::Prefix(_) | Component::RootDir))
+        };
+
+        if leftover_root(&self_comps[shared..]) || leftover_root(&base_comps[shared..]) {
+            return None;
+        }
+
+        if base_comps[shared..].iter().any(|c| matches!(c, Component::ParentDir)) {
+            return None;
+        }
+
+        let mut relative = PathBuf::new();
+        for _ in &base_comps[shared..] {
+            relative.push("..");
+        }
+        for component in &self_comps[shared..] {
+            relative.push(component.as_os_str());
+        }
+
+        if relative.as_os_str().is_empty() {
+            relative.push(".");
+        }
+
+        Some(RPath { path: relative })
+    }
+
+    ///
     /// Invokes `clear` on the underlying `PathBuf`
     /// 
     /// ### Usage
@@ -465,6 +885,38 @@ impl RPath{
     pub fn is_symlink(&self) -> bool {
         self.path.is_symlink()
     }
+
+    ///
+    /// returns `true` if the RPath starts with `base` else `false`
+    /// ### Usage
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp/abc.txt");
+    /// if rpath.starts_with("/temp") {
+    ///     // do something
+    /// }
+    /// ```
+    #[cfg(feature = "Boolean")]
+    pub fn starts_with<T: AsRef<Path>>(&self, base: T) -> bool {
+        self.path.starts_with(base)
+    }
+
+    ///
+    /// returns `true` if the RPath ends with `child` else `false`
+    /// ### Usage
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp/abc.txt");
+    /// if rpath.ends_with("abc.txt") {
+    ///     // do something
+    /// }
+    /// ```
+    #[cfg(feature = "Boolean")]
+    pub fn ends_with<T: AsRef<Path>>(&self, child: T) -> bool {
+        self.path.ends_with(child)
+    }
 }
 
 // as ref
@@ -474,6 +926,139 @@ impl AsRef<RPath> for RPath {
     }
 }
 
+// Lets an `RPath` be passed anywhere a `T: AsRef<Path>` bound is expected (e.g. `join`,
+// `relative_to`), the same way `&str`/`Path`/`PathBuf` already can be.
+impl AsRef<Path> for RPath {
+    fn as_ref(&self) -> &Path {
+        self.path.as_path()
+    }
+}
+
+///
+/// An `RPath` that is statically known to be absolute.
+///
+/// Following rust-analyzer's `AbsPathBuf`/`AbsPath` design, this lets the type system catch the
+/// common bug of passing a not-yet-expanded relative path into an API that assumed an absolute
+/// base. Obtain one via `RPath::into_absolute()` or `TryFrom<PathBuf>`.
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg(feature = "Management")]
+pub struct AbsRPath(RPath);
+
+///
+/// An `RPath` that is statically known to be relative.
+///
+/// Obtain one via `RPath::into_relative()` or `TryFrom<PathBuf>`.
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg(feature = "Management")]
+pub struct RelRPath(RPath);
+
+#[cfg(feature = "Management")]
+impl RPath {
+    ///
+    /// Converts this `RPath` into an `AbsRPath`, succeeding only when `is_absolute()` is `true`.
+    /// On failure, the original `RPath` is handed back unchanged.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("/temp/abc.txt");
+    /// assert!(rpath.into_absolute().is_ok());
+    /// ```
+    pub fn into_absolute(self) -> Result<AbsRPath, RPath> {
+        if self.is_absolute() {
+            Ok(AbsRPath(self))
+        } else {
+            Err(self)
+        }
+    }
+
+    ///
+    /// Converts this `RPath` into a `RelRPath`, succeeding only when `is_relative()` is `true`.
+    /// On failure, the original `RPath` is handed back unchanged.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let rpath = RPath::from("temp/abc.txt");
+    /// assert!(rpath.into_relative().is_ok());
+    /// ```
+    pub fn into_relative(self) -> Result<RelRPath, RPath> {
+        if self.is_relative() {
+            Ok(RelRPath(self))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+#[cfg(feature = "Management")]
+impl TryFrom<PathBuf> for AbsRPath {
+    type Error = RPath;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        RPath::from(path).into_absolute()
+    }
+}
+
+#[cfg(feature = "Management")]
+impl TryFrom<PathBuf> for RelRPath {
+    type Error = RPath;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        RPath::from(path).into_relative()
+    }
+}
+
+#[cfg(feature = "Management")]
+impl AbsRPath {
+    ///
+    /// Borrows the underlying `RPath`.
+    ///
+    pub fn as_rpath(&self) -> &RPath {
+        &self.0
+    }
+
+    ///
+    /// Joins a `RelRPath` onto this absolute path, producing another `AbsRPath`.
+    ///
+    /// ### Usage
+    ///
+    /// ```
+    /// use rustypath::RPath;
+    ///
+    /// let base = RPath::from("/temp").into_absolute().unwrap();
+    /// let rel = RPath::from("abc.txt").into_relative().unwrap();
+    ///
+    /// assert_eq!(base.join(&rel).as_rpath(), &RPath::from("/temp/abc.txt"));
+    /// ```
+    pub fn join(&self, rel: &RelRPath) -> AbsRPath {
+        AbsRPath(RPath { path: self.0.path.join(&rel.0.path) })
+    }
+
+    ///
+    /// Returns an iterator over the entries within this directory. See `RPath::read_dir`.
+    ///
+    pub fn read_dir(&self) -> std::io::Result<std::fs::ReadDir> {
+        self.0.read_dir()
+    }
+}
+
+#[cfg(feature = "Management")]
+impl RelRPath {
+    ///
+    /// Borrows the underlying `RPath`.
+    ///
+    pub fn as_rpath(&self) -> &RPath {
+        &self.0
+    }
+}
+
 pub trait Display {
     fn print(&self);
 
@@ -505,4 +1090,75 @@ impl IntoPy<PyObject> for RPath {
     fn into_py(self, py: pyo3::Python<'_>) -> PyObject {
         PyString::intern_bound(py, &self.convert_to_string()).into()
     }
+}
+
+// Serializing a native `PathBuf` is a portability hazard: Windows backslashes and drive
+// prefixes leak into manifests (TOML/JSON) that must also load on Unix. `RPath` is instead
+// serialized through a normalized, forward-slash form and reconstructed into a platform-native
+// `PathBuf` on the way back in.
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+impl Serialize for RPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut portable = String::new();
+        for component in self.path.components() {
+            match component {
+                Component::Prefix(prefix) => {
+                    // A Windows drive/UNC prefix has no portable forward-slash representation;
+                    // silently dropping it would change the path's location rather than just its
+                    // separator, so surface it as a hard serialization error instead.
+                    return Err(serde::ser::Error::custom(format!(
+                        "RPath {:?} has a platform-specific prefix ({:?}) that cannot be serialized portably",
+                        self.path, prefix,
+                    )));
+                },
+                Component::RootDir => portable.push('/'),
+                Component::CurDir => {
+                    if !portable.is_empty() && !portable.ends_with('/') { portable.push('/'); }
+                    portable.push('.');
+                },
+                Component::ParentDir => {
+                    if !portable.is_empty() && !portable.ends_with('/') { portable.push('/'); }
+                    portable.push_str("..");
+                },
+                Component::Normal(segment) => {
+                    if !portable.is_empty() && !portable.ends_with('/') { portable.push('/'); }
+                    portable.push_str(&segment.to_string_lossy());
+                },
+            }
+        }
+
+        serializer.serialize_str(&portable)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let portable = String::deserialize(deserializer)?;
+
+        let mut path = PathBuf::new();
+        for segment in portable.split('/') {
+            if segment.is_empty() {
+                continue;
+            }
+            path.push(segment);
+        }
+
+        if portable.starts_with('/') {
+            let mut rooted = PathBuf::from("/");
+            rooted.push(path);
+            path = rooted;
+        }
+
+        Ok(RPath { path })
+    }
 }
\ No newline at end of file